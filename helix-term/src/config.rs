@@ -1,5 +1,6 @@
 use crate::keymap;
 use crate::keymap::{merge_keys, KeyTrie};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use helix_loader::merge_toml_values;
 use helix_view::document::Mode;
 use serde::Deserialize;
@@ -7,9 +8,10 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::fs;
 use std::io::Error as IOError;
+use std::path::{Path, PathBuf};
 use toml::de::Error as TomlError;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Config {
     pub theme: Option<String>,
     // todo: key might be smt. else?
@@ -18,15 +20,58 @@ pub struct Config {
     pub keys_lang: HashMap<String, HashMap<Mode, KeyTrie>>,
     pub editor: helix_view::editor::Config,
     pub editor_lang: HashMap<String, helix_view::editor::Config>,
+    /// Path-glob scoped overrides (the `match` key on a `[[languages]]`
+    /// entry), in declaration order. Resolved on top of the language-name
+    /// overrides above: base -> language-name override -> glob overrides.
+    /// The patterns are kept alongside the `GlobSet` compiled from them
+    /// (which has no `PartialEq`) so `Config`'s own `PartialEq` impl can
+    /// tell two otherwise-identical overrides with different `match`
+    /// patterns apart.
+    pub glob_overrides: Vec<(Vec<String>, GlobSet, GlobConfig)>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+/// The theme/keys/editor overrides declared by a glob-scoped `[[languages]]`
+/// entry. Unlike `theme_lang`/`keys_lang`/`editor_lang`, these are left
+/// unmerged with the base config: they are deltas to layer on top at
+/// document-open time once a path is known to match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobConfig {
+    pub theme: Option<String>,
+    pub keys: Option<HashMap<Mode, KeyTrie>>,
+    pub editor: Option<toml::Value>,
+}
+
+impl PartialEq for Config {
+    fn eq(&self, other: &Self) -> bool {
+        // `GlobSet` has no `PartialEq` impl, so compare the patterns it was
+        // compiled from (kept alongside it) and the overrides it guards,
+        // rather than the compiled matcher itself.
+        self.theme == other.theme
+            && self.theme_lang == other.theme_lang
+            && self.keys == other.keys
+            && self.keys_lang == other.keys_lang
+            && self.editor == other.editor
+            && self.editor_lang == other.editor_lang
+            && self.glob_overrides.len() == other.glob_overrides.len()
+            && self
+                .glob_overrides
+                .iter()
+                .zip(other.glob_overrides.iter())
+                .all(|((pa, _, a), (pb, _, b))| pa == pb && a == b)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ConfigRaw {
     pub theme: Option<String>,
     pub keys: Option<HashMap<Mode, KeyTrie>>,
     pub editor: Option<toml::Value>,
     pub languages: Option<Vec<LanguageConfigRaw>>,
+    /// Other config files to merge into this one before it is merged with
+    /// its siblings, resolved relative to this file's directory. Later
+    /// includes override earlier ones, and this file overrides all of them.
+    pub include: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -36,8 +81,64 @@ pub struct LanguageConfigRaw {
     pub theme: Option<String>,
     pub keys: Option<HashMap<Mode, KeyTrie>>,
     pub editor: Option<toml::Value>,
+    /// Glob patterns (e.g. `["**/*.test.ts", "scripts/*"]`) that scope this
+    /// block to matching paths instead of the language named by `name`.
+    #[serde(rename = "match")]
+    pub match_globs: Option<Vec<String>>,
 }
 
+/// A single recoverable problem found while parsing a config file in
+/// lenient mode (see `Config::load_default_lenient`): an unknown or
+/// otherwise rejected key, where it was found, and what the user probably
+/// meant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigDiagnostic {
+    /// 1-indexed line in the source file, or 0 if unknown (e.g. the error
+    /// comes from validating an already-merged value with no source text).
+    pub line: usize,
+    /// 1-indexed column in the source file, or 0 if unknown.
+    pub column: usize,
+    pub key: String,
+    /// The known field closest to `key` by Levenshtein distance, if any is
+    /// close enough to be worth suggesting.
+    pub suggestion: Option<String>,
+    pub message: String,
+}
+
+const CONFIG_RAW_FIELDS: &[&str] = &["theme", "keys", "editor", "languages", "include"];
+const LANGUAGE_CONFIG_RAW_FIELDS: &[&str] = &["name", "theme", "keys", "editor", "match"];
+// `helix_view::editor::Config` lives in another crate, so its fields can't
+// be enumerated by reflection; this list is kept in sync with it by hand.
+const EDITOR_CONFIG_FIELDS: &[&str] = &[
+    "scrolloff",
+    "scroll-lines",
+    "mouse",
+    "middle-click-paste",
+    "shell",
+    "line-number",
+    "cursorline",
+    "cursorcolumn",
+    "gutters",
+    "auto-completion",
+    "auto-format",
+    "auto-save",
+    "idle-timeout",
+    "completion-trigger-len",
+    "auto-info",
+    "file-picker",
+    "statusline",
+    "cursor-shape",
+    "true-color",
+    "search",
+    "lsp",
+    "terminal",
+    "rulers",
+    "whitespace",
+    "bufferline",
+    "indent-guides",
+    "color-modes",
+];
+
 impl Default for Config {
     fn default() -> Config {
         Config {
@@ -47,6 +148,7 @@ impl Default for Config {
             keys_lang: HashMap::new(),
             editor: helix_view::editor::Config::default(),
             editor_lang: HashMap::new(),
+            glob_overrides: Vec::new(),
         }
     }
 }
@@ -55,6 +157,12 @@ impl Default for Config {
 pub enum ConfigLoadError {
     BadConfig(TomlError),
     Error(IOError),
+    IncludeCycle(PathBuf),
+    /// An `include = [...]` entry named a file that could not be read. Kept
+    /// distinct from `Error` so that callers don't mistake it for a
+    /// top-level config file simply not existing (which is optional and
+    /// silently skipped) — an explicit include target is not optional.
+    IncludeNotFound(PathBuf, IOError),
 }
 
 impl Default for ConfigLoadError {
@@ -68,95 +176,147 @@ impl Display for ConfigLoadError {
         match self {
             ConfigLoadError::BadConfig(err) => err.fmt(f),
             ConfigLoadError::Error(err) => err.fmt(f),
+            ConfigLoadError::IncludeCycle(path) => {
+                write!(f, "include cycle detected at {}", path.display())
+            }
+            ConfigLoadError::IncludeNotFound(path, err) => {
+                write!(f, "included file {} could not be read: {}", path.display(), err)
+            }
         }
     }
 }
 
 impl Config {
-    pub fn load(
-        global: Result<String, ConfigLoadError>,
-        local: Result<String, ConfigLoadError>,
-    ) -> Result<Config, ConfigLoadError> {
-        let global_config: Result<ConfigRaw, ConfigLoadError> =
-            global.and_then(|file| toml::from_str(&file).map_err(ConfigLoadError::BadConfig));
-        let local_config: Result<ConfigRaw, ConfigLoadError> =
-            local.and_then(|file| toml::from_str(&file).map_err(ConfigLoadError::BadConfig));
-
-        let res = match (global_config, local_config) {
-            (Ok(mut global), Ok(mut local)) => {
-                let keys = Self::merge_config_keys(keymap::default(), global.keys, local.keys);
-
-                let editor_value = Self::merge_editor_toml(global.editor, local.editor);
-
-                let (theme_lang, keys_lang, editor_lang) = Self::get_lang_maps(
-                    Self::get_lang_config_map(global.languages.take()),
-                    Self::get_lang_config_map(local.languages.take()),
-                    &keys,
-                    &editor_value,
-                )?;
-
-                let editor = Self::map_editor_config(editor_value)?;
-
-                Config {
-                    theme: local.theme.or(global.theme),
-                    keys,
-                    editor,
-                    theme_lang,
-                    keys_lang,
-                    editor_lang,
-                }
-            }
-            // if any configs are invalid return that first
-            (_, Err(ConfigLoadError::BadConfig(err)))
-            | (Err(ConfigLoadError::BadConfig(err)), _) => {
-                return Err(ConfigLoadError::BadConfig(err))
-            }
-            (Ok(mut config), Err(_)) | (Err(_), Ok(mut config)) => {
-                let keys = Self::merge_config_keys(keymap::default(), config.keys, None);
-
-                let (theme_lang, keys_lang, editor_lang) = Self::get_lang_maps(
-                    Self::get_lang_config_map(config.languages.take()),
-                    HashMap::new(),
-                    &keys,
-                    &config.editor,
-                )?;
-
-                let editor = Self::map_editor_config(config.editor)?;
-
-                Config {
-                    theme: config.theme,
-                    keys,
-                    editor,
-                    theme_lang,
-                    keys_lang,
-                    editor_lang,
-                }
-            }
+    /// Merges an ordered list of config sources into a single `Config`.
+    ///
+    /// Sources are listed from lowest to highest precedence (e.g. the global
+    /// config first, down to the most specific directory-local config last):
+    /// each layer's keymap, editor settings and language overrides are merged
+    /// on top of the previous ones, with later layers winning.
+    pub fn load(configs: Vec<Result<String, ConfigLoadError>>) -> Result<Config, ConfigLoadError> {
+        let mut raw_configs = Vec::with_capacity(configs.len());
+        let mut io_err = None;
 
-            // these are just two io errors return the one for the global config
-            (Err(err), Err(_)) => return Err(err),
-        };
+        for config in configs {
+            match config {
+                Ok(file) => raw_configs
+                    .push(toml::from_str(&file).map_err(ConfigLoadError::BadConfig)?),
+                Err(ConfigLoadError::BadConfig(err)) => return Err(ConfigLoadError::BadConfig(err)),
+                Err(err) => io_err.get_or_insert(err),
+            };
+        }
+
+        if raw_configs.is_empty() {
+            return Err(io_err.unwrap_or_default());
+        }
+
+        Self::merge_raw_configs(raw_configs)
+    }
 
-        Ok(res)
+    fn merge_raw_configs(mut raw_configs: Vec<ConfigRaw>) -> Result<Config, ConfigLoadError> {
+        let theme = raw_configs.iter_mut().fold(None, |theme, config| {
+            config.theme.take().or(theme)
+        });
+
+        let keys = raw_configs.iter_mut().fold(keymap::default(), |keys, config| {
+            Self::merge_config_keys(keys, config.keys.take())
+        });
+
+        let editor_value = raw_configs
+            .iter_mut()
+            .fold(None, |editor, config| {
+                Self::merge_editor_toml(editor, config.editor.take())
+            });
+
+        let mut lang_configs = Vec::with_capacity(raw_configs.len());
+        let mut glob_configs = Vec::new();
+        for config in &mut raw_configs {
+            let (names, globs) = Self::get_lang_config_map(config.languages.take());
+            lang_configs.push(names);
+            glob_configs.extend(globs);
+        }
+
+        let (theme_lang, keys_lang, editor_lang) =
+            Self::get_lang_maps(lang_configs, &keys, &editor_value)?;
+
+        let glob_overrides = Self::get_glob_overrides(glob_configs)?;
+
+        let editor = Self::map_editor_config(editor_value)?;
+
+        Ok(Config {
+            theme,
+            keys,
+            editor,
+            theme_lang,
+            keys_lang,
+            editor_lang,
+            glob_overrides,
+        })
     }
 
+    /// Splits a layer's `[[languages]]` entries into name-keyed overrides and
+    /// glob-scoped ones (those declaring `match`), which are resolved
+    /// separately by `get_glob_overrides`.
     fn get_lang_config_map(
         languages: Option<Vec<LanguageConfigRaw>>,
-    ) -> HashMap<String, LanguageConfigRaw> {
-        languages.map_or_else(
-            || HashMap::new(),
-            |languages| {
-                languages
-                    .into_iter()
-                    .map(|lang| (lang.name.clone(), lang))
-                    .collect()
-            },
-        )
+    ) -> (HashMap<String, LanguageConfigRaw>, Vec<LanguageConfigRaw>) {
+        let mut names = HashMap::new();
+        let mut globs = Vec::new();
+
+        for lang in languages.into_iter().flatten() {
+            if lang.match_globs.is_some() {
+                globs.push(lang);
+            } else {
+                names.insert(lang.name.clone(), lang);
+            }
+        }
+
+        (names, globs)
+    }
+
+    /// Compiles each glob-scoped `[[languages]]` entry into a `GlobSet` and
+    /// its declared overrides, preserving declaration order.
+    fn get_glob_overrides(
+        glob_configs: Vec<LanguageConfigRaw>,
+    ) -> Result<Vec<(Vec<String>, GlobSet, GlobConfig)>, ConfigLoadError> {
+        glob_configs
+            .into_iter()
+            .map(|lang| {
+                let patterns = lang.match_globs.clone().unwrap_or_default();
+                let glob_set = Self::compile_glob_set(lang.match_globs.unwrap_or_default())?;
+                Ok((
+                    patterns,
+                    glob_set,
+                    GlobConfig {
+                        theme: lang.theme,
+                        keys: lang.keys,
+                        editor: lang.editor,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    fn compile_glob_set(patterns: Vec<String>) -> Result<GlobSet, ConfigLoadError> {
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in patterns {
+            let glob = Glob::new(&pattern)
+                .map_err(|err| ConfigLoadError::Error(IOError::new(std::io::ErrorKind::InvalidInput, err)))?;
+            builder.add(glob);
+        }
+
+        builder
+            .build()
+            .map_err(|err| ConfigLoadError::Error(IOError::new(std::io::ErrorKind::InvalidInput, err)))
     }
 
+    /// Resolves the per-language theme/keys/editor overrides across an
+    /// ordered list of language config maps, one per config layer (lowest to
+    /// highest precedence), the same way `merge_raw_configs` resolves the
+    /// top-level config.
     fn get_lang_maps(
-        mut lang_global: HashMap<String, LanguageConfigRaw>,
-        mut lang_local: HashMap<String, LanguageConfigRaw>,
+        mut lang_configs: Vec<HashMap<String, LanguageConfigRaw>>,
         merged_keys: &HashMap<Mode, KeyTrie>,
         editor_value: &Option<toml::Value>,
     ) -> Result<
@@ -171,74 +331,51 @@ impl Config {
         let mut keys_lang = HashMap::new();
         let mut editor_lang = HashMap::new();
 
-        let language_names: HashSet<String> = lang_global
-            .keys()
-            .chain(lang_local.keys())
-            .cloned()
+        let language_names: HashSet<String> = lang_configs
+            .iter()
+            .flat_map(|langs| langs.keys().cloned())
             .collect();
 
         for lang in language_names {
-            let (mut theme, mut keys, mut editor) =
-                match (lang_global.get_mut(&lang), lang_local.get_mut(&lang)) {
-                    (None, Some(lang_conf)) | (Some(lang_conf), None) => {
-                        let keys = lang_conf
-                            .keys
-                            .take()
-                            .map(|k| Self::merge_config_keys(merged_keys.clone(), Some(k), None));
-
-                        let editor = if lang_conf.editor.is_some() {
-                            Some(Self::map_editor_config(Self::merge_editor_toml(
-                                editor_value.clone(),
-                                lang_conf.editor.take(),
-                            ))?)
-                        } else {
-                            None
-                        };
-
-                        (lang_conf.theme.take(), keys, editor)
-                    }
-                    (Some(lang_global), Some(lang_local)) => {
-                        let keys = if lang_global.keys.is_some() || lang_local.keys.is_some() {
-                            Some(Self::merge_config_keys(
-                                merged_keys.clone(),
-                                lang_global.keys.take(),
-                                lang_local.keys.take(),
-                            ))
-                        } else {
-                            None
-                        };
-
-                        let editor = if lang_global.editor.is_some() || lang_local.editor.is_some()
-                        {
-                            Some(Self::map_editor_config(Self::merge_editor_toml(
-                                editor_value.clone(),
-                                Self::merge_editor_toml(
-                                    lang_global.editor.take(),
-                                    lang_local.editor.take(),
-                                ),
-                            ))?)
-                        } else {
-                            None
-                        };
-
-                        (
-                            lang_local.theme.take().or(lang_global.theme.take()),
-                            keys,
-                            editor,
-                        )
-                    }
-                    (..) => (None, None, None),
+            let mut theme = None;
+            let mut keys: Option<HashMap<Mode, KeyTrie>> = None;
+            let mut editor_override: Option<toml::Value> = None;
+
+            for lang_config in &mut lang_configs {
+                let lang_conf = match lang_config.get_mut(&lang) {
+                    Some(lang_conf) => lang_conf,
+                    None => continue,
                 };
 
-            if let Some(theme) = theme.take() {
+                if lang_conf.theme.is_some() {
+                    theme = lang_conf.theme.take();
+                }
+
+                if let Some(layer_keys) = lang_conf.keys.take() {
+                    keys = Some(Self::merge_config_keys(
+                        keys.unwrap_or_else(|| merged_keys.clone()),
+                        Some(layer_keys),
+                    ));
+                }
+
+                if let Some(layer_editor) = lang_conf.editor.take() {
+                    editor_override = Self::merge_editor_toml(editor_override, Some(layer_editor));
+                }
+            }
+
+            if let Some(theme) = theme {
                 theme_lang.insert(lang.clone(), theme);
             }
 
-            if let Some(keys) = keys.take() {
+            if let Some(keys) = keys {
                 keys_lang.insert(lang.clone(), keys);
             }
 
-            if let Some(editor) = editor.take() {
+            if editor_override.is_some() {
+                let editor = Self::map_editor_config(Self::merge_editor_toml(
+                    editor_value.clone(),
+                    editor_override,
+                ))?;
                 editor_lang.insert(lang, editor);
             }
         }
@@ -248,27 +385,23 @@ impl Config {
 
     fn merge_config_keys(
         mut dst: HashMap<Mode, KeyTrie>,
-        global_keys: Option<HashMap<Mode, KeyTrie>>,
-        local_keys: Option<HashMap<Mode, KeyTrie>>,
+        keys: Option<HashMap<Mode, KeyTrie>>,
     ) -> HashMap<Mode, KeyTrie> {
-        if let Some(global_keys) = global_keys {
-            merge_keys(&mut dst, global_keys)
-        }
-        if let Some(local_keys) = local_keys {
-            merge_keys(&mut dst, local_keys)
+        if let Some(keys) = keys {
+            merge_keys(&mut dst, keys)
         }
 
         dst
     }
 
     fn merge_editor_toml(
-        global_editor: Option<toml::Value>,
-        local_editor: Option<toml::Value>,
+        base_editor: Option<toml::Value>,
+        override_editor: Option<toml::Value>,
     ) -> Option<toml::Value> {
-        match (global_editor, local_editor) {
+        match (base_editor, override_editor) {
             (None, None) => None,
             (None, Some(val)) | (Some(val), None) => Some(val),
-            (Some(global), Some(local)) => Some(merge_toml_values(global, local, 3)),
+            (Some(base), Some(over)) => Some(merge_toml_values(base, over, 3)),
         }
     }
 
@@ -284,11 +417,452 @@ impl Config {
     }
 
     pub fn load_default() -> Result<Config, ConfigLoadError> {
-        let global_config =
-            fs::read_to_string(helix_loader::config_file()).map_err(ConfigLoadError::Error);
-        let local_config = fs::read_to_string(helix_loader::workspace_config_file())
-            .map_err(ConfigLoadError::Error);
-        Config::load(global_config, local_config)
+        let mut paths = vec![helix_loader::config_file()];
+        paths.extend(Self::directory_config_files());
+
+        let mut raw_configs = Vec::with_capacity(paths.len());
+        let mut io_err = None;
+
+        for path in paths {
+            // Each top-level file gets its own `ancestors` stack: the same
+            // shared include is allowed to appear under several layers, and
+            // even twice in the same layer's include graph (e.g. a diamond),
+            // only a file including itself, directly or transitively, is
+            // rejected.
+            match Self::resolve_config_path(&path, &mut Vec::new()) {
+                Ok(config) => raw_configs.push(config),
+                Err(ConfigLoadError::Error(err)) => io_err.get_or_insert(ConfigLoadError::Error(err)),
+                Err(err) => return Err(err),
+            };
+        }
+
+        if raw_configs.is_empty() {
+            return Err(io_err.unwrap_or_default());
+        }
+
+        Self::merge_raw_configs(raw_configs)
+    }
+
+    /// Like `load_default`, but never fails outright on a malformed config:
+    /// unknown keys are stripped and reported as `ConfigDiagnostic`s (with a
+    /// "did you mean" suggestion) instead of aborting the whole load, and a
+    /// best-effort `Config` is returned alongside them.
+    ///
+    /// `include` directives are not expanded in this mode — an included file
+    /// losing its position information once merged would make diagnostics
+    /// impossible to point at the right line, so lenient parsing is limited
+    /// to each top-level config file on its own. A file that declares
+    /// `include` gets a diagnostic noting its included content was dropped,
+    /// rather than disappearing silently.
+    pub fn load_default_lenient() -> (Config, Vec<ConfigDiagnostic>) {
+        let mut paths = vec![helix_loader::config_file()];
+        paths.extend(Self::directory_config_files());
+
+        let mut raw_configs = Vec::new();
+        let mut diagnostics = Vec::new();
+
+        for path in paths {
+            let file = match fs::read_to_string(&path) {
+                Ok(file) => file,
+                Err(_) => continue,
+            };
+
+            let (mut config, mut file_diagnostics) = Self::parse_config_raw_lenient(&file);
+            diagnostics.append(&mut file_diagnostics);
+
+            if let Some(includes) = config.include.take().filter(|includes| !includes.is_empty()) {
+                diagnostics.push(ConfigDiagnostic {
+                    line: 0,
+                    column: 0,
+                    key: "include".to_owned(),
+                    suggestion: None,
+                    message: format!(
+                        "{} does not expand `include` in lenient mode; dropped: {}",
+                        path.display(),
+                        includes.join(", "),
+                    ),
+                });
+            }
+
+            raw_configs.push(config);
+        }
+
+        if raw_configs.is_empty() {
+            return (Config::default(), diagnostics);
+        }
+
+        let config = Self::merge_raw_configs_lenient(raw_configs, &mut diagnostics);
+        (config, diagnostics)
+    }
+
+    /// Merges `raw_configs` layer by layer, the same way `merge_raw_configs`
+    /// merges all of them at once, except that a layer whose contribution
+    /// can't be reconciled — a known key with a value of the wrong type
+    /// (`scrolloff = "two"`), or a malformed `match` glob pattern — is
+    /// reported as a diagnostic and dropped instead of discarding every
+    /// other layer's theme/keys/editor/language settings along with it.
+    /// `strip_unknown_editor_keys` already handles the more common case of
+    /// a typo'd editor key per file, before this ever runs.
+    fn merge_raw_configs_lenient(
+        raw_configs: Vec<ConfigRaw>,
+        diagnostics: &mut Vec<ConfigDiagnostic>,
+    ) -> Config {
+        let mut accepted: Vec<ConfigRaw> = Vec::with_capacity(raw_configs.len());
+        let mut config = Config::default();
+
+        for raw in raw_configs {
+            let mut candidate = accepted.clone();
+            candidate.push(raw.clone());
+
+            match Self::merge_raw_configs(candidate) {
+                Ok(merged) => {
+                    config = merged;
+                    accepted.push(raw);
+                }
+                Err(err) => diagnostics.push(ConfigDiagnostic {
+                    line: 0,
+                    column: 0,
+                    key: String::new(),
+                    suggestion: None,
+                    message: format!("dropping a config layer that failed to merge: {err}"),
+                }),
+            }
+        }
+
+        config
+    }
+
+    /// Parses `file` into a `ConfigRaw`, tolerating unknown keys (at the
+    /// top level or inside a `[[languages]]` block) by blanking out the
+    /// offending line and reparsing, collecting a diagnostic for each one
+    /// instead of failing on the first.
+    ///
+    /// `[editor]`/`[languages.editor]` tables are untyped `toml::Value`s, so
+    /// `deny_unknown_fields` never rejects a typo'd key inside them here —
+    /// it would otherwise only surface once the merged value is converted
+    /// to `helix_view::editor::Config`, by which point it's too late to
+    /// recover anything less than the whole merged `Config`. Strip those
+    /// keys up front instead.
+    fn parse_config_raw_lenient(file: &str) -> (ConfigRaw, Vec<ConfigDiagnostic>) {
+        let mut lines: Vec<&str> = file.lines().collect();
+        let mut diagnostics = Vec::new();
+
+        Self::strip_unknown_editor_keys(&mut lines, &mut diagnostics);
+
+        loop {
+            let candidate = lines.join("\n");
+
+            match toml::from_str::<ConfigRaw>(&candidate) {
+                Ok(config) => return (config, diagnostics),
+                Err(err) => {
+                    let Some((line, _)) = err.line_col() else {
+                        diagnostics.push(ConfigDiagnostic {
+                            line: 0,
+                            column: 0,
+                            key: String::new(),
+                            suggestion: None,
+                            message: err.to_string(),
+                        });
+                        return (ConfigRaw::default(), diagnostics);
+                    };
+
+                    let Some(key) = Self::unknown_field_from_error(&err) else {
+                        let (line, column) = err.line_col().unwrap_or((0, 0));
+                        diagnostics.push(ConfigDiagnostic {
+                            line: line + 1,
+                            column: column + 1,
+                            key: String::new(),
+                            suggestion: None,
+                            message: err.to_string(),
+                        });
+                        return (ConfigRaw::default(), diagnostics);
+                    };
+
+                    let (_, column) = err.line_col().unwrap_or((line, 0));
+                    let known_fields = Self::known_fields_near(&lines, line);
+
+                    diagnostics.push(ConfigDiagnostic {
+                        line: line + 1,
+                        column: column + 1,
+                        key: key.clone(),
+                        suggestion: Self::suggest_field(&key, known_fields),
+                        message: err.to_string(),
+                    });
+
+                    if line >= lines.len() {
+                        return (ConfigRaw::default(), diagnostics);
+                    }
+                    lines[line] = "";
+                }
+            }
+        }
+    }
+
+    /// Blanks out any key inside a `[editor]` or `[languages.editor]` table
+    /// that isn't in `EDITOR_CONFIG_FIELDS`, recording a diagnostic for each
+    /// one. Unlike the rest of `ConfigRaw`, those tables parse as an opaque
+    /// `toml::Value` and so never trip `deny_unknown_fields` on their own.
+    fn strip_unknown_editor_keys(lines: &mut [&str], diagnostics: &mut Vec<ConfigDiagnostic>) {
+        for line in 0..lines.len() {
+            let Some(key) = Self::key_value_line(lines[line]) else {
+                continue;
+            };
+
+            // A dotted key (`lsp.display-messages = true`) sets a field of the
+            // table named by its first segment.
+            let field = key.split('.').next().unwrap_or(key).trim();
+            let known_fields = Self::known_fields_near(&*lines, line);
+            if known_fields != EDITOR_CONFIG_FIELDS || known_fields.contains(&field) {
+                continue;
+            }
+
+            let column = lines[line].len() - lines[line].trim_start().len() + 1;
+            diagnostics.push(ConfigDiagnostic {
+                line: line + 1,
+                column,
+                key: key.to_owned(),
+                suggestion: Self::suggest_field(key, known_fields),
+                message: format!("unknown field `{key}` in `[editor]`"),
+            });
+            lines[line] = "";
+        }
+    }
+
+    /// If `line` looks like a `key = value` assignment, returns the
+    /// (unquoted) key. Returns `None` for blank lines, comments and table
+    /// headers.
+    fn key_value_line(line: &str) -> Option<&str> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('[') {
+            return None;
+        }
+
+        let (key, _) = trimmed.split_once('=')?;
+        let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+        (!key.is_empty()).then_some(key)
+    }
+
+    /// Extracts the offending key from a serde `deny_unknown_fields` error
+    /// message (`"unknown field \`foo\`, expected one of ..."`).
+    fn unknown_field_from_error(err: &TomlError) -> Option<String> {
+        let message = err.to_string();
+        let start = message.find("unknown field `")? + "unknown field `".len();
+        let rest = &message[start..];
+        let end = rest.find('`')?;
+        Some(rest[..end].to_owned())
+    }
+
+    /// Picks the known-field list an unknown key at `line` most likely
+    /// belongs to, by looking upward for the nearest table header.
+    fn known_fields_near(lines: &[&str], line: usize) -> &'static [&'static str] {
+        for prior in lines[..line.min(lines.len())].iter().rev() {
+            let trimmed = prior.trim();
+            if trimmed.starts_with("[[languages]]") {
+                return LANGUAGE_CONFIG_RAW_FIELDS;
+            }
+            if trimmed.starts_with("[editor]") || trimmed.starts_with("[languages.editor]") {
+                return EDITOR_CONFIG_FIELDS;
+            }
+            if trimmed.starts_with('[') {
+                break;
+            }
+        }
+
+        CONFIG_RAW_FIELDS
+    }
+
+    /// Finds the known field closest to `key` by Levenshtein distance, if
+    /// one is close enough to plausibly be what the user meant.
+    fn suggest_field(key: &str, known_fields: &[&str]) -> Option<String> {
+        const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+        known_fields
+            .iter()
+            .map(|field| (*field, Self::levenshtein_distance(key, field)))
+            .min_by_key(|(_, distance)| *distance)
+            .filter(|(_, distance)| *distance <= MAX_SUGGESTION_DISTANCE)
+            .map(|(field, _)| field.to_owned())
+    }
+
+    fn levenshtein_distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+
+        for i in 1..=a.len() {
+            let mut prev_diag = row[0];
+            row[0] = i;
+
+            for j in 1..=b.len() {
+                let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+                let above_left = prev_diag;
+                prev_diag = row[j];
+                row[j] = (row[j] + 1).min(row[j - 1] + 1).min(above_left + cost);
+            }
+        }
+
+        row[b.len()]
+    }
+
+    /// Reads and parses the config file at `path`, recursively resolving its
+    /// `include`d files (relative to `path`'s directory) and merging them in
+    /// with the same before-wins-less precedence as `merge_config_keys` and
+    /// `merge_editor_toml`: later includes override earlier ones, and `path`
+    /// itself overrides all of its includes.
+    ///
+    /// `ancestors` is the stack of canonical paths currently being resolved
+    /// (i.e. `path` and everything that (transitively) included it), used to
+    /// reject a genuine cycle. It is pushed before recursing into an include
+    /// and popped afterwards, so a diamond — two files independently
+    /// including the same, non-cyclic third file — is not mistaken for one.
+    fn resolve_config_path(
+        path: &Path,
+        ancestors: &mut Vec<PathBuf>,
+    ) -> Result<ConfigRaw, ConfigLoadError> {
+        let file = fs::read_to_string(path).map_err(ConfigLoadError::Error)?;
+        let canonical = fs::canonicalize(path).map_err(ConfigLoadError::Error)?;
+
+        if ancestors.contains(&canonical) {
+            return Err(ConfigLoadError::IncludeCycle(canonical));
+        }
+
+        ancestors.push(canonical);
+        let result = Self::resolve_included_config(path, &file, ancestors);
+        ancestors.pop();
+        result
+    }
+
+    fn resolve_included_config(
+        path: &Path,
+        file: &str,
+        ancestors: &mut Vec<PathBuf>,
+    ) -> Result<ConfigRaw, ConfigLoadError> {
+        let mut config: ConfigRaw = toml::from_str(file).map_err(ConfigLoadError::BadConfig)?;
+        let includes = config.include.take().unwrap_or_default();
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+        let merged_includes = includes.into_iter().try_fold(
+            ConfigRaw::default(),
+            |merged, include| -> Result<ConfigRaw, ConfigLoadError> {
+                let resolved = Self::resolve_include(&dir.join(include), ancestors)?;
+                Ok(Self::merge_two_raw(merged, resolved))
+            },
+        )?;
+
+        Ok(Self::merge_two_raw(merged_includes, config))
+    }
+
+    /// Resolves an `include` target, translating a bare "file not found" (or
+    /// other IO error) from the included file into
+    /// `ConfigLoadError::IncludeNotFound`. Without this, a typo'd include
+    /// path produces the exact same `ConfigLoadError::Error` as a top-level
+    /// config file simply not existing, so `load_default` would silently
+    /// treat the whole layer as absent instead of surfacing the mistake.
+    fn resolve_include(
+        path: &Path,
+        ancestors: &mut Vec<PathBuf>,
+    ) -> Result<ConfigRaw, ConfigLoadError> {
+        Self::resolve_config_path(path, ancestors).map_err(|err| match err {
+            ConfigLoadError::Error(io_err) => {
+                ConfigLoadError::IncludeNotFound(path.to_path_buf(), io_err)
+            }
+            other => other,
+        })
+    }
+
+    /// Merges two `ConfigRaw`s with `over` taking precedence over `base`.
+    fn merge_two_raw(base: ConfigRaw, over: ConfigRaw) -> ConfigRaw {
+        ConfigRaw {
+            theme: over.theme.or(base.theme),
+            keys: Self::merge_raw_keys(base.keys, over.keys),
+            editor: Self::merge_editor_toml(base.editor, over.editor),
+            languages: match (base.languages, over.languages) {
+                (None, None) => None,
+                (Some(langs), None) | (None, Some(langs)) => Some(langs),
+                (Some(base_langs), Some(over_langs)) => {
+                    Some(Self::merge_language_configs(base_langs, over_langs))
+                }
+            },
+            include: None,
+        }
+    }
+
+    fn merge_raw_keys(
+        base: Option<HashMap<Mode, KeyTrie>>,
+        over: Option<HashMap<Mode, KeyTrie>>,
+    ) -> Option<HashMap<Mode, KeyTrie>> {
+        match (base, over) {
+            (None, None) => None,
+            (Some(keys), None) | (None, Some(keys)) => Some(keys),
+            (Some(mut base), Some(over)) => {
+                merge_keys(&mut base, over);
+                Some(base)
+            }
+        }
+    }
+
+    /// Merges two layers' `[[languages]]` entries by `(name, match)` rather
+    /// than `name` alone: a glob-scoped override (`match = [...]`) and a
+    /// plain name-keyed one for the same language are distinct entries (see
+    /// `get_lang_config_map`), so collapsing them by name would silently
+    /// hand one's `match_globs` to the other, turning an intended
+    /// glob-scoped override into an unconditional one or vice versa.
+    fn merge_language_configs(
+        base: Vec<LanguageConfigRaw>,
+        over: Vec<LanguageConfigRaw>,
+    ) -> Vec<LanguageConfigRaw> {
+        let mut merged = base;
+
+        for over_lang in over {
+            let existing = merged.iter_mut().find(|lang| {
+                lang.name == over_lang.name && lang.match_globs == over_lang.match_globs
+            });
+
+            match existing {
+                Some(base_lang) => {
+                    base_lang.theme = over_lang.theme.or_else(|| base_lang.theme.take());
+                    base_lang.keys = Self::merge_raw_keys(base_lang.keys.take(), over_lang.keys);
+                    base_lang.editor =
+                        Self::merge_editor_toml(base_lang.editor.take(), over_lang.editor);
+                }
+                None => merged.push(over_lang),
+            }
+        }
+
+        merged
+    }
+
+    /// Returns the `.helix/config.toml` files found by walking from the
+    /// current working directory up to the workspace root, ordered from the
+    /// workspace root down to the current directory so that the config
+    /// closest to the current directory takes precedence.
+    fn directory_config_files() -> Vec<PathBuf> {
+        let (workspace, _) = helix_loader::find_workspace();
+        let cwd = std::env::current_dir().unwrap_or_else(|_| workspace.clone());
+
+        Self::directory_config_files_between(&workspace, &cwd)
+    }
+
+    /// The directory-walking half of `directory_config_files`, split out so
+    /// it can be tested against a real directory tree without depending on
+    /// the process's current directory or `helix_loader::find_workspace`.
+    fn directory_config_files_between(workspace: &Path, cwd: &Path) -> Vec<PathBuf> {
+        let mut dirs = vec![cwd.to_path_buf()];
+        let mut dir: &Path = cwd;
+        while dir != workspace {
+            match dir.parent() {
+                Some(parent) => {
+                    dirs.push(parent.to_path_buf());
+                    dir = parent;
+                }
+                None => break,
+            }
+        }
+        dirs.reverse();
+
+        dirs.into_iter()
+            .map(|dir| dir.join(".helix").join("config.toml"))
+            .collect()
     }
 }
 
@@ -298,7 +872,7 @@ mod tests {
 
     impl Config {
         fn load_test(config: &str) -> Config {
-            Config::load(Ok(config.to_owned()), Err(ConfigLoadError::default())).unwrap()
+            Config::load(vec![Ok(config.to_owned())]).unwrap()
         }
     }
 
@@ -350,4 +924,339 @@ mod tests {
         let default_keys = Config::default().keys;
         assert_eq!(default_keys, keymap::default());
     }
+
+    #[test]
+    fn cascading_configs_let_nearest_override() {
+        let global = r#"
+            theme = "base16_default"
+
+            [editor]
+            scrolloff = 2
+        "#;
+
+        let nested = r#"
+            theme = "nord"
+
+            [editor]
+            line-number = "relative"
+        "#;
+
+        let config = Config::load(vec![Ok(global.to_owned()), Ok(nested.to_owned())]).unwrap();
+
+        assert_eq!(config.theme, Some("nord".to_owned()));
+        assert_eq!(config.editor.scrolloff, 2);
+        assert_eq!(
+            config.editor.line_number,
+            helix_view::editor::LineNumber::Relative
+        );
+    }
+
+    #[test]
+    fn directory_config_files_are_ordered_root_to_leaf() {
+        let workspace = temp_config_dir("dir-walk");
+        let project = workspace.join("project");
+        let src = project.join("src");
+        fs::create_dir_all(&src).unwrap();
+
+        let found = Config::directory_config_files_between(&workspace, &src);
+
+        assert_eq!(
+            found,
+            vec![
+                workspace.join(".helix").join("config.toml"),
+                project.join(".helix").join("config.toml"),
+                src.join(".helix").join("config.toml"),
+            ]
+        );
+    }
+
+    fn temp_config_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("helix-config-test-{}", name));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn include_merges_included_file_with_including_file_winning() {
+        let dir = temp_config_dir("include");
+
+        fs::write(
+            dir.join("keys.toml"),
+            r#"
+                theme = "base16_default"
+
+                [editor]
+                scrolloff = 2
+            "#,
+        )
+        .unwrap();
+
+        let config_path = dir.join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+                include = ["keys.toml"]
+                theme = "nord"
+            "#,
+        )
+        .unwrap();
+
+        let config = Config::resolve_config_path(&config_path, &mut Vec::new()).unwrap();
+
+        assert_eq!(config.theme, Some("nord".to_owned()));
+        assert!(config.include.is_none());
+    }
+
+    #[test]
+    fn include_does_not_clobber_a_glob_scoped_language_entry_sharing_a_name() {
+        let dir = temp_config_dir("include-glob");
+
+        fs::write(
+            dir.join("lang.toml"),
+            r#"
+                [[languages]]
+                name = "typescript"
+                match = ["vendor/*"]
+
+                [languages.editor]
+                auto-format = false
+            "#,
+        )
+        .unwrap();
+
+        let config_path = dir.join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+                include = ["lang.toml"]
+
+                [[languages]]
+                name = "typescript"
+
+                [languages.editor]
+                rulers = [120]
+            "#,
+        )
+        .unwrap();
+
+        let resolved = Config::resolve_config_path(&config_path, &mut Vec::new()).unwrap();
+        let languages = resolved.languages.unwrap();
+
+        assert_eq!(languages.len(), 2);
+        let (name_only, glob_scoped): (Vec<_>, Vec<_>) =
+            languages.iter().partition(|lang| lang.match_globs.is_none());
+        assert_eq!(name_only.len(), 1);
+        assert_eq!(glob_scoped.len(), 1);
+        assert_eq!(
+            glob_scoped[0].match_globs,
+            Some(vec!["vendor/*".to_owned()])
+        );
+    }
+
+    #[test]
+    fn missing_include_target_is_a_hard_error() {
+        let dir = temp_config_dir("missing-include");
+
+        let config_path = dir.join("config.toml");
+        fs::write(&config_path, r#"include = ["does-not-exist.toml"]"#).unwrap();
+
+        let result = Config::resolve_config_path(&config_path, &mut Vec::new());
+
+        assert!(matches!(result, Err(ConfigLoadError::IncludeNotFound(..))));
+    }
+
+    #[test]
+    fn include_cycle_is_rejected() {
+        let dir = temp_config_dir("cycle");
+
+        fs::write(dir.join("a.toml"), r#"include = ["b.toml"]"#).unwrap();
+        fs::write(dir.join("b.toml"), r#"include = ["a.toml"]"#).unwrap();
+
+        let result = Config::resolve_config_path(&dir.join("a.toml"), &mut Vec::new());
+
+        assert!(matches!(result, Err(ConfigLoadError::IncludeCycle(_))));
+    }
+
+    #[test]
+    fn diamond_shaped_includes_are_not_a_cycle() {
+        let dir = temp_config_dir("diamond");
+
+        fs::write(
+            dir.join("common.toml"),
+            r#"
+                [editor]
+                scrolloff = 2
+            "#,
+        )
+        .unwrap();
+        fs::write(dir.join("a.toml"), r#"include = ["common.toml"]"#).unwrap();
+        fs::write(dir.join("b.toml"), r#"include = ["common.toml"]"#).unwrap();
+
+        let config_path = dir.join("config.toml");
+        fs::write(&config_path, r#"include = ["a.toml", "b.toml"]"#).unwrap();
+
+        let config = Config::resolve_config_path(&config_path, &mut Vec::new()).unwrap();
+
+        assert!(config.editor.is_some());
+    }
+
+    #[test]
+    fn glob_scoped_language_config_produces_a_glob_override() {
+        let sample_config = r#"
+            [[languages]]
+            name = "typescript"
+            match = ["**/*.test.ts", "scripts/*"]
+
+            [languages.editor]
+            rulers = [120]
+        "#;
+
+        let config = Config::load_test(sample_config);
+
+        assert_eq!(config.glob_overrides.len(), 1);
+        let (patterns, globs, glob_override) = &config.glob_overrides[0];
+        assert_eq!(patterns, &vec!["**/*.test.ts".to_owned(), "scripts/*".to_owned()]);
+        assert!(globs.is_match("src/foo.test.ts"));
+        assert!(globs.is_match("scripts/build"));
+        assert!(!globs.is_match("src/foo.ts"));
+        assert!(glob_override.editor.is_some());
+    }
+
+    #[test]
+    fn configs_with_different_glob_patterns_are_not_equal() {
+        let ts_config = Config::load_test(
+            r#"
+            [[languages]]
+            name = "typescript"
+            match = ["**/*.test.ts"]
+
+            [languages.editor]
+            rulers = [120]
+        "#,
+        );
+
+        let rust_config = Config::load_test(
+            r#"
+            [[languages]]
+            name = "typescript"
+            match = ["**/*.rs"]
+
+            [languages.editor]
+            rulers = [120]
+        "#,
+        );
+
+        assert_ne!(ts_config, rust_config);
+    }
+
+    #[test]
+    fn lenient_parse_reports_unknown_top_level_key_with_suggestion() {
+        let sample_config = r#"
+            thme = "nord"
+
+            [editor]
+            scrolloff = 2
+        "#;
+
+        let (config, diagnostics) = Config::parse_config_raw_lenient(sample_config);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "thme");
+        assert_eq!(diagnostics[0].suggestion.as_deref(), Some("theme"));
+        assert!(config.editor.is_some());
+    }
+
+    #[test]
+    fn lenient_parse_recovers_across_multiple_bad_keys() {
+        let sample_config = r#"
+            thme = "nord"
+
+            [[languages]]
+            name = "rust"
+            thmee = "nord"
+        "#;
+
+        let (config, diagnostics) = Config::parse_config_raw_lenient(sample_config);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(config.languages.unwrap()[0].name, "rust");
+    }
+
+    #[test]
+    fn lenient_parse_strips_unknown_editor_key_without_discarding_rest() {
+        let sample_config = r#"
+            theme = "nord"
+
+            [editor]
+            scrolloff = 2
+            scrollofff = 3
+        "#;
+
+        let (config, diagnostics) = Config::parse_config_raw_lenient(sample_config);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "scrollofff");
+        assert_eq!(diagnostics[0].suggestion.as_deref(), Some("scrolloff"));
+
+        let merged = Config::merge_raw_configs(vec![config]).unwrap();
+        assert_eq!(merged.theme, Some("nord".to_owned()));
+        assert_eq!(merged.editor.scrolloff, 2);
+    }
+
+    #[test]
+    fn lenient_parse_strips_unknown_language_editor_key() {
+        let sample_config = r#"
+            [[languages]]
+            name = "rust"
+
+            [languages.editor]
+            rulers = [100]
+            rulerz = [120]
+        "#;
+
+        let (config, diagnostics) = Config::parse_config_raw_lenient(sample_config);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "rulerz");
+        assert_eq!(diagnostics[0].suggestion.as_deref(), Some("rulers"));
+
+        let merged = Config::merge_raw_configs(vec![config]).unwrap();
+        assert!(merged.editor_lang.contains_key("rust"));
+    }
+
+    #[test]
+    fn lenient_merge_drops_only_the_layer_with_a_bad_value_type() {
+        let good_layer: ConfigRaw = toml::from_str(
+            r#"
+                theme = "nord"
+
+                [editor]
+                scrolloff = 2
+            "#,
+        )
+        .unwrap();
+
+        let bad_layer: ConfigRaw = toml::from_str(
+            r#"
+                [editor]
+                scrolloff = "two"
+            "#,
+        )
+        .unwrap();
+
+        let mut diagnostics = Vec::new();
+        let config =
+            Config::merge_raw_configs_lenient(vec![good_layer, bad_layer], &mut diagnostics);
+
+        assert_eq!(config.theme, Some("nord".to_owned()));
+        assert_eq!(config.editor.scrolloff, 2);
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn levenshtein_distance_matches_known_cases() {
+        assert_eq!(Config::levenshtein_distance("theme", "theme"), 0);
+        assert_eq!(Config::levenshtein_distance("thme", "theme"), 1);
+        assert_eq!(Config::levenshtein_distance("kitten", "sitting"), 3);
+    }
 }